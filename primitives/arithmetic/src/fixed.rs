@@ -19,6 +19,7 @@ use serde::{Serialize, Deserialize};
 
 use sp_std::{ops, fmt, prelude::*, convert::TryInto};
 use codec::{Encode, CompactAs};
+use primitive_types::U256;
 use crate::traits::{
 	SaturatedConversion, UniqueSaturatedInto, Saturating, BaseArithmetic,
 	Bounded, Zero, FixedPointNumber
@@ -111,13 +112,54 @@ macro_rules! implement_fixed {
 			where
 				N: Copy + TryFrom<Self::Inner> + TryInto<Self::Inner>,
 			{
-				N::try_into(*other)
-					.ok()
-					.and_then(|n| self.0.checked_div(n))
-					.and_then(|n| n.checked_div(Self::DIV))
-					.and_then(|n| TryInto::<N>::try_into(n).ok())
+				let (dividend, divisor, signum) = self.div_int_parts(other)?;
+
+				dividend.checked_div(divisor)
+					.and_then(|n| TryInto::<Self::Inner>::try_into(n).ok())
+					.and_then(|n| TryInto::<N>::try_into(n * signum).ok())
 			}
-		
+
+			// Same as `checked_div_int`, but rounds the quotient to the nearest integer
+			// (half away from zero) instead of truncating toward zero.
+			fn checked_div_int_round<N>(&self, other: &N) -> Option<N>
+			where
+				N: Copy + TryFrom<Self::Inner> + TryInto<Self::Inner>,
+			{
+				let (dividend, divisor, signum) = self.div_int_parts(other)?;
+
+				let quotient = dividend.checked_div(divisor)?;
+				let remainder = dividend - quotient * divisor;
+				let quotient = if remainder.saturating_mul(U256::from(2u32)) >= divisor {
+					quotient + U256::one()
+				} else {
+					quotient
+				};
+
+				TryInto::<Self::Inner>::try_into(quotient).ok()
+					.and_then(|n| TryInto::<N>::try_into(n * signum).ok())
+			}
+
+			fn saturating_div_int<N>(&self, other: &N) -> N
+			where
+				N: Copy + TryFrom<Self::Inner> + TryInto<Self::Inner> + Bounded + Signed,
+			{
+				if let Ok(rhs) = N::try_into(*other) {
+					let rhs: Self::Inner = rhs;
+					if rhs == 0 {
+						panic!("attempt to divide by zero");
+					}
+				}
+
+				self.checked_div_int(other).unwrap_or_else(|| {
+					let signum = other.signum().saturated_into() * self.0.signum();
+					if signum.is_negative() {
+						Bounded::min_value()
+					} else {
+						Bounded::max_value()
+					}
+				})
+			}
+
 			fn saturating_mul_int<N>(&self, other: &N) -> N
 			where
 				N: Copy + TryFrom<Self::Inner> + TryInto<Self::Inner> + Bounded + Signed,
@@ -194,6 +236,72 @@ macro_rules! implement_fixed {
 					int.saturating_sub(excess)
 				}
 			}
+
+			fn trunc(&self) -> Self {
+				let inner = self.0.checked_div(Self::DIV)
+					.expect("self.0 is divided by a non-zero constant; qed")
+					.saturating_mul(Self::DIV);
+				Self::from_inner(inner)
+			}
+
+			fn frac(&self) -> Self {
+				let integer = self.trunc();
+				(*self).saturating_sub(integer)
+			}
+
+			fn floor(&self) -> Self {
+				let integer = self.trunc();
+				let frac = self.frac();
+
+				if self.is_negative() && !frac.is_zero() {
+					Self::from_inner(integer.0.saturating_sub(Self::DIV))
+				} else {
+					integer
+				}
+			}
+
+			fn ceil(&self) -> Self {
+				let integer = self.trunc();
+				let frac = self.frac();
+
+				if self.is_positive() && !frac.is_zero() {
+					Self::from_inner(integer.0.saturating_add(Self::DIV))
+				} else {
+					integer
+				}
+			}
+
+			fn round(&self) -> Self {
+				let integer = self.trunc();
+				let frac = self.frac();
+
+				// `frac` has the same sign as `self`, so comparing its doubled absolute
+				// value against `DIV` decides whether we round away from zero.
+				let double_frac = frac.0.saturating_abs().saturating_mul(2);
+				if double_frac < Self::DIV {
+					integer
+				} else if self.is_negative() {
+					Self::from_inner(integer.0.saturating_sub(Self::DIV))
+				} else {
+					Self::from_inner(integer.0.saturating_add(Self::DIV))
+				}
+			}
+
+			fn trunc_int(&self) -> Self::Inner {
+				self.trunc().into_inner() / Self::DIV
+			}
+
+			fn floor_int(&self) -> Self::Inner {
+				self.floor().into_inner() / Self::DIV
+			}
+
+			fn ceil_int(&self) -> Self::Inner {
+				self.ceil().into_inner() / Self::DIV
+			}
+
+			fn round_int(&self) -> Self::Inner {
+				self.round().into_inner() / Self::DIV
+			}
 		}
 
 		impl Saturating for $name {
@@ -240,7 +348,7 @@ macro_rules! implement_fixed {
 			type Output = Self;
 
 			fn add(self, rhs: Self) -> Self::Output {
-				Self(self.0 + rhs.0)
+				self.checked_add(&rhs).expect("attempt to add with overflow")
 			}
 		}
 
@@ -248,7 +356,7 @@ macro_rules! implement_fixed {
 			type Output = Self;
 
 			fn sub(self, rhs: Self) -> Self::Output {
-				Self(self.0 - rhs.0)
+				self.checked_sub(&rhs).expect("attempt to subtract with overflow")
 			}
 		}
 
@@ -256,7 +364,7 @@ macro_rules! implement_fixed {
 			type Output = Self;
 
 			fn mul(self, rhs: Self) -> Self::Output {
-				Self((self.0 * rhs.0) / Self::DIV)
+				self.checked_mul(&rhs).expect("attempt to multiply with overflow")
 			}
 		}
 
@@ -264,7 +372,194 @@ macro_rules! implement_fixed {
 			type Output = Self;
 
 			fn div(self, rhs: Self) -> Self::Output {
-				Self((self.0 * Self::DIV) / rhs.0)
+				if rhs.0 == 0 {
+					panic!("attempt to divide by zero");
+				}
+				self.checked_div(&rhs).expect("attempt to divide with overflow")
+			}
+		}
+
+		impl $name {
+			// Shared by `checked_div_int`/`checked_div_int_round`: `self / other` as an
+			// unsigned `U256` dividend/divisor pair plus the sign of the result.
+			fn div_int_parts<N>(&self, other: &N) -> Option<(U256, U256, <Self as FixedPointNumber>::Inner)>
+			where
+				N: Copy + TryFrom<<Self as FixedPointNumber>::Inner> + TryInto<<Self as FixedPointNumber>::Inner>,
+			{
+				N::try_into(*other).ok().and_then(|rhs| {
+					if rhs == 0 {
+						return None;
+					}
+
+					let lhs = Self::unsigned_magnitude(self.0);
+					let mut signum = self.0.signum();
+					if rhs.is_negative() {
+						signum = signum * -1;
+					}
+					let rhs = Self::unsigned_magnitude(rhs);
+
+					// `rhs * DIV` computed in `U256` first, so it doesn't truncate.
+					let divisor = U256::from(rhs).checked_mul(U256::from(Self::DIV as u128))?;
+					let dividend = U256::from(lhs);
+
+					Some((dividend, divisor, signum))
+				})
+			}
+
+			// Carry the intermediate product of `a * b` in `U256` before dividing by `c`,
+			// so operands up to `Self::Inner::MAX` in magnitude don't silently truncate.
+			fn multiply_by_rational_u256(a: u128, b: u128, c: u128) -> Option<u128> {
+				U256::from(a)
+					.checked_mul(U256::from(b))
+					.and_then(|n| n.checked_div(U256::from(c)))
+					.and_then(|n| n.try_into().ok())
+			}
+
+			// `x.abs()` as `u128`; `Inner::min_value()` has no representable positive
+			// counterpart in `Inner`, so it's special-cased rather than negated.
+			fn unsigned_magnitude(x: <Self as FixedPointNumber>::Inner) -> u128 {
+				if x == <Self as FixedPointNumber>::Inner::min_value() {
+					<Self as FixedPointNumber>::Inner::max_value() as u128 + 1
+				} else if x.is_negative() {
+					x.saturating_mul(-1) as u128
+				} else {
+					x as u128
+				}
+			}
+
+			// Reapplies `signum` to the unsigned `n` from `unsigned_magnitude`. `n` can equal
+			// `unsigned_magnitude(Inner::min_value())`, which doesn't fit a positive `Inner`,
+			// so that exact magnitude is mapped straight to `Inner::min_value()` when negative.
+			fn signed_from_magnitude(n: u128, signum: <Self as FixedPointNumber>::Inner) -> Option<<Self as FixedPointNumber>::Inner> {
+				if signum.is_negative() && n == Self::unsigned_magnitude(<Self as FixedPointNumber>::Inner::min_value()) {
+					return Some(<Self as FixedPointNumber>::Inner::min_value());
+				}
+				TryInto::<<Self as FixedPointNumber>::Inner>::try_into(n).ok().map(|m| m * signum)
+			}
+
+			// Newton's method integer square root.
+			fn integer_sqrt_u256(n: U256) -> U256 {
+				if n.is_zero() {
+					return U256::zero();
+				}
+
+				let mut x = n;
+				let mut y = (x + U256::one()) >> 1;
+				while y < x {
+					x = y;
+					y = (x + n / x) >> 1;
+				}
+				x
+			}
+
+			pub fn checked_sqrt(&self) -> Option<Self> {
+				if self.is_negative() {
+					return None;
+				}
+				if self.is_zero() {
+					return Some(*self);
+				}
+
+				// sqrt(inner / DIV) == sqrt(inner * DIV) / DIV, so the root's inner is sqrt(inner * DIV).
+				let radicand = U256::from(self.0 as u128) * U256::from(Self::DIV as u128);
+				let root = Self::integer_sqrt_u256(radicand);
+
+				TryInto::<Self::Inner>::try_into(root).ok().map(Self::from_inner)
+			}
+
+			pub fn sqrt(&self) -> Self {
+				self.checked_sqrt().expect("sqrt of a negative fixed-point value is undefined")
+			}
+
+			// `e`, to 16 significant digits.
+			fn e() -> Self {
+				Self::from_rational(2_718281828459045i64, 1_000000000000000)
+			}
+
+			// `ln(2)`, to 15 significant digits.
+			fn ln_2() -> Self {
+				Self::from_rational(693147180559945i64, 1_000000000000000)
+			}
+
+			pub fn exp(&self) -> Self {
+				// `self == k + r`, so `exp(self) == e.pow(k) * exp(r)`.
+				let floor = self.floor();
+				let r = self.saturating_sub(floor);
+				let k = floor.into_inner() / Self::DIV;
+
+				// `exp(r) = 1 + r + r^2/2! + r^3/3! + ...`.
+				let mut term = Self::one();
+				let mut exp_r = Self::one();
+				let mut n: Self::Inner = 0;
+				for _ in 0..12 {
+					n += 1;
+					term = match term.checked_mul(&r).and_then(|t| t.checked_div(&Self::from_integer(n))) {
+						Some(t) => t,
+						None => break,
+					};
+					exp_r = exp_r.saturating_add(term);
+				}
+
+				if k == 0 {
+					return exp_r;
+				}
+
+				let negative = k < 0;
+				let magnitude = if negative { (0 as Self::Inner) - k } else { k };
+				let magnitude: usize = TryInto::<usize>::try_into(magnitude).unwrap_or(usize::max_value());
+				let e_pow_k = Self::e().saturating_pow(magnitude);
+
+				let result = if negative {
+					exp_r.checked_div(&e_pow_k)
+				} else {
+					exp_r.checked_mul(&e_pow_k)
+				};
+
+				result.unwrap_or_else(|| if negative { Self::zero() } else { Self::max_value() })
+			}
+
+			pub fn checked_ln(&self) -> Option<Self> {
+				if !self.is_positive() {
+					return None;
+				}
+
+				let one = Self::one();
+				if *self == one {
+					return Some(Self::zero());
+				}
+
+				// Normalize into the mantissa range `[1, 2)`, tracking the scaling as `exponent`.
+				let div = U256::from(Self::DIV as u128);
+				let mut mantissa = U256::from(self.0 as u128);
+				let mut exponent: Self::Inner = 0;
+				while mantissa >= div * 2 {
+					mantissa /= 2;
+					exponent += 1;
+				}
+				while mantissa < div {
+					mantissa *= 2;
+					exponent -= 1;
+				}
+
+				let mantissa = Self::from_inner(TryInto::<Self::Inner>::try_into(mantissa).ok()?);
+
+				// `ln(x) = 2 * atanh(t) = 2 * (t + t^3/3 + t^5/5 + ...)`, `t = (x - 1) / (x + 1)`.
+				let t = mantissa.saturating_sub(one).checked_div(&mantissa.saturating_add(one))?;
+				let t2 = t.checked_mul(&t)?;
+
+				let mut term = t;
+				let mut sum = t;
+				let mut n: Self::Inner = 1;
+				for _ in 0..10 {
+					term = term.checked_mul(&t2)?;
+					n += 2;
+					sum = sum.saturating_add(term.checked_div(&Self::from_integer(n))?);
+				}
+
+				let ln_mantissa = sum.saturating_mul(Self::from_integer(2));
+				let ln_exponent = Self::from_integer(exponent).checked_mul(&Self::ln_2())?;
+
+				Some(ln_mantissa.saturating_add(ln_exponent))
 			}
 		}
 
@@ -291,40 +586,24 @@ macro_rules! implement_fixed {
 				}
 
 				let signum = self.0.signum() / rhs.0.signum();
-				let mut lhs = self.0;
-				if lhs.is_negative() {
-					lhs = lhs.saturating_mul(-1);
-				}
-
-				let mut rhs = rhs.0;
-				if rhs.is_negative() {
-					rhs = rhs.saturating_mul(-1);
-				}
+				let lhs = Self::unsigned_magnitude(self.0);
+				let rhs = Self::unsigned_magnitude(rhs.0);
 
-				multiply_by_rational(lhs as u128, <Self as FixedPointNumber>::DIV as u128, rhs as u128)
-					.ok()
-					.and_then(|n| TryInto::<<Self as FixedPointNumber>::Inner>::try_into(n).ok())
-					.map(|n| Self(n * signum))
+				Self::multiply_by_rational_u256(lhs, <Self as FixedPointNumber>::DIV as u128, rhs)
+					.and_then(|n| Self::signed_from_magnitude(n, signum))
+					.map(Self)
 			}
 		}
 
 		impl CheckedMul for $name {
 			fn checked_mul(&self, rhs: &Self) -> Option<Self> {
 				let signum = self.0.signum() * rhs.0.signum();
-				let mut lhs = self.0;
+				let lhs = Self::unsigned_magnitude(self.0);
+				let rhs = Self::unsigned_magnitude(rhs.0);
 
-				if lhs.is_negative() {
-					lhs = lhs.saturating_mul(-1);
-				}
-				let mut rhs = rhs.0;
-				if rhs.is_negative() {
-					rhs = rhs.saturating_mul(-1);
-				}
-
-				multiply_by_rational(lhs as u128, rhs as u128, <Self as FixedPointNumber>::DIV as u128)
-					.ok()
-					.and_then(|n| TryInto::<<Self as FixedPointNumber>::Inner>::try_into(n).ok())
-					.map(|n| Self(n * signum))
+				Self::multiply_by_rational_u256(lhs, rhs, <Self as FixedPointNumber>::DIV as u128)
+					.and_then(|n| Self::signed_from_magnitude(n, signum))
+					.map(Self)
 			}
 		}
 
@@ -503,6 +782,199 @@ macro_rules! implement_fixed {
 				let c = a * b;
 				assert_eq!(c, b);
 			}
+
+			#[test]
+			fn checked_mul_does_not_truncate_for_large_operands() {
+				// A plain `u128` product (the old path) overflows before `DIV` brings it
+				// back down; only a `U256` intermediate survives.
+				let a = $name::from_integer(10_000_000_000);
+				let b = $name::from_integer(10_000_000_000);
+
+				assert_eq!(a.checked_mul(&b), Some($name::from_integer(100_000_000_000_000_000_000)));
+
+				// A product that genuinely doesn't fit `Self::Inner` still reports `None`,
+				// rather than wrapping.
+				assert_eq!(max().checked_mul(&$name::from_integer(2)), None);
+			}
+
+			#[test]
+			fn checked_mul_handles_min_value_operand() {
+				assert_eq!(min().checked_mul(&$name::from_integer(1)), Some(min()));
+				assert_eq!(min().checked_mul(&$name::from_integer(-1)), None);
+			}
+
+			#[test]
+			fn checked_div_does_not_truncate_for_large_operands() {
+				let a = $name::from_integer(100_000_000_000_000_000_000);
+				let b = $name::from_integer(10_000_000_000);
+
+				assert_eq!(a.checked_div(&b), Some($name::from_integer(10_000_000_000)));
+
+				// Division by zero still reports `None`.
+				assert_eq!($name::from_integer(1).checked_div(&$name::zero()), None);
+			}
+
+			#[test]
+			fn checked_div_handles_min_value_operand() {
+				assert_eq!(min().checked_div(&$name::from_integer(1)), Some(min()));
+				assert_eq!(min().checked_div(&$name::from_integer(-1)), None);
+				assert_eq!(min().checked_div(&min()), Some($name::from_integer(1)));
+			}
+
+			#[test]
+			#[should_panic(expected = "attempt to add with overflow")]
+			fn add_panics_on_overflow() {
+				let _ = max() + $name::from_integer(1);
+			}
+
+			#[test]
+			#[should_panic(expected = "attempt to subtract with overflow")]
+			fn sub_panics_on_overflow() {
+				let _ = min() - $name::from_integer(1);
+			}
+
+			#[test]
+			#[should_panic(expected = "attempt to multiply with overflow")]
+			fn mul_panics_on_overflow() {
+				let _ = max() * $name::from_integer(2);
+			}
+
+			#[test]
+			#[should_panic(expected = "attempt to divide by zero")]
+			fn div_panics_on_zero() {
+				let _ = $name::from_integer(1) / $name::zero();
+			}
+
+			#[test]
+			#[should_panic(expected = "attempt to divide with overflow")]
+			fn div_panics_on_overflow() {
+				let _ = min() / $name::from_integer(-1);
+			}
+
+			#[test]
+			fn trunc_frac_floor_ceil_works() {
+				let a = $name::from_rational(5, 2);
+				let b = $name::from_rational(-5, 2);
+
+				assert_eq!(a.trunc(), $name::from_integer(2));
+				assert_eq!(b.trunc(), $name::from_integer(-2));
+
+				assert_eq!(a.frac().saturating_mul_int(10), 5.into());
+				assert_eq!(b.frac().saturating_mul_int(10), -5.into());
+
+				assert_eq!(a.floor(), $name::from_integer(2));
+				assert_eq!(b.floor(), $name::from_integer(-3));
+
+				assert_eq!(a.ceil(), $name::from_integer(3));
+				assert_eq!(b.ceil(), $name::from_integer(-2));
+
+				assert_eq!($name::from_integer(2).trunc(), $name::from_integer(2));
+				assert_eq!($name::from_integer(2).frac(), $name::zero());
+			}
+
+			#[test]
+			fn round_works() {
+				let a = $name::from_rational(5, 2);
+				let b = $name::from_rational(-5, 2);
+				let c = $name::from_rational(9, 4);
+				let d = $name::from_rational(-9, 4);
+
+				assert_eq!(a.round(), $name::from_integer(3));
+				assert_eq!(b.round(), $name::from_integer(-3));
+				assert_eq!(c.round(), $name::from_integer(2));
+				assert_eq!(d.round(), $name::from_integer(-2));
+			}
+
+			#[test]
+			fn trunc_frac_floor_ceil_round_saturate_at_bounds() {
+				assert_eq!(max().ceil(), max());
+				assert_eq!(max().round(), max());
+				assert_eq!(min().floor(), min());
+				assert_eq!(min().round(), min());
+			}
+
+			#[test]
+			fn sqrt_works() {
+				assert_eq!($name::from_integer(4).sqrt(), $name::from_integer(2));
+				assert_eq!($name::from_integer(0).sqrt(), $name::from_integer(0));
+				assert_eq!($name::from_integer(1).sqrt(), $name::from_integer(1));
+
+				let expected = $name::from_rational(3, 2);
+				let diff = ($name::from_rational(9, 4).sqrt().into_inner() - expected.into_inner()).abs();
+				assert!(diff <= 1);
+			}
+
+			#[test]
+			fn checked_sqrt_returns_none_for_negative() {
+				assert_eq!($name::from_integer(-1).checked_sqrt(), None);
+			}
+
+			#[test]
+			fn exp_works() {
+				let tolerance = $name::accuracy() / 1000;
+
+				assert_eq!($name::from_integer(0).exp(), $name::from_integer(1));
+
+				let e = $name::from_integer(1).exp();
+				let diff = (e.into_inner() - $name::e().into_inner()).abs();
+				assert!(diff <= tolerance);
+
+				let e_squared = $name::from_integer(2).exp();
+				let expected = $name::e() * $name::e();
+				let diff = (e_squared.into_inner() - expected.into_inner()).abs();
+				assert!(diff <= tolerance * 2);
+
+				// A fractional input, so the Taylor series is actually exercised.
+				let exp_1_5 = $name::from_rational(3, 2).exp();
+				let expected = $name::from_rational(4_481689070338065, 1_000000000000000);
+				let diff = (exp_1_5.into_inner() - expected.into_inner()).abs();
+				assert!(diff <= tolerance * 5);
+
+				let exp_neg_1_5 = $name::from_rational(-3, 2).exp();
+				let expected = $name::from_rational(223130160148430, 1_000000000000000);
+				let diff = (exp_neg_1_5.into_inner() - expected.into_inner()).abs();
+				assert!(diff <= tolerance * 5);
+			}
+
+			#[test]
+			fn checked_ln_works() {
+				assert_eq!($name::from_integer(-1).checked_ln(), None);
+				assert_eq!($name::from_integer(0).checked_ln(), None);
+				assert_eq!($name::from_integer(1).checked_ln(), Some($name::zero()));
+
+				let tolerance = $name::accuracy() / 1000;
+				let ln_e = $name::e().checked_ln().expect("e is positive; qed");
+				let diff = (ln_e.into_inner() - $name::from_integer(1).into_inner()).abs();
+				assert!(diff <= tolerance);
+			}
+
+			#[test]
+			fn checked_div_int_works() {
+				let a = $name::from_rational(10, 4); // 2.5
+				let b = $name::from_rational(-10, 4); // -2.5
+
+				assert_eq!(a.checked_div_int(&2), Some(1));
+				assert_eq!(b.checked_div_int(&2), Some(-1));
+				assert_eq!(a.checked_div_int(&0), None);
+
+				assert_eq!(a.checked_div_int_round(&2), Some(1));
+				assert_eq!(b.checked_div_int_round(&2), Some(-1));
+				assert_eq!($name::from_integer(3).checked_div_int_round(&2), Some(2));
+				assert_eq!($name::from_integer(-3).checked_div_int_round(&2), Some(-2));
+			}
+
+			#[test]
+			fn saturating_div_int_works() {
+				let a = $name::from_rational(10, 4); // 2.5
+
+				assert_eq!(a.saturating_div_int(&2), a.checked_div_int(&2).unwrap());
+			}
+
+			#[test]
+			#[should_panic(expected = "attempt to divide by zero")]
+			fn saturating_div_int_panics_on_zero() {
+				let _ = $name::from_integer(1).saturating_div_int(&0);
+			}
 		}
 	}
 }
\ No newline at end of file